@@ -0,0 +1,172 @@
+//! The `.far` archive format: a flat, self-contained container for a single
+//! bucket's files. Layout is a small header, an 8-byte-aligned index of
+//! `(path, offset, length)` entries, then the concatenated file contents.
+
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"FAR1";
+
+/// One entry in an archive's index.
+pub struct ArchiveEntry {
+    pub path: String,
+    pub offset: u64,
+    pub length: u64,
+}
+
+fn pad_to_8(writer: &mut impl Write, written: &mut u64) -> io::Result<()> {
+    let padding = (8 - (*written % 8)) % 8;
+    writer.write_all(&vec![0u8; padding as usize])?;
+    *written += padding;
+    Ok(())
+}
+
+/// Write `entries` (paired with the source paths their contents are read from) into a single
+/// `.far` archive at `archive_path`.
+pub fn write_archive(archive_path: &Path, entries: &[(String, &Path, u64)]) -> io::Result<()> {
+    let mut out = File::create(archive_path)?;
+
+    out.write_all(MAGIC)?;
+    out.write_all(&(entries.len() as u32).to_le_bytes())?;
+    let mut written: u64 = MAGIC.len() as u64 + 4;
+
+    // First pass: figure out the data offsets by sizing the index.
+    let mut index_size: u64 = 0;
+    for (path, _, _) in entries {
+        index_size += 4 + path.len() as u64;
+        let padding = (8 - (index_size % 8)) % 8;
+        index_size += padding + 16;
+    }
+
+    let mut data_offset = written + index_size;
+    let mut offsets = Vec::with_capacity(entries.len());
+    for (_, _, length) in entries {
+        offsets.push(data_offset);
+        data_offset += length;
+    }
+
+    for ((path, _, length), offset) in entries.iter().zip(&offsets) {
+        out.write_all(&(path.len() as u32).to_le_bytes())?;
+        out.write_all(path.as_bytes())?;
+        written += 4 + path.len() as u64;
+        pad_to_8(&mut out, &mut written)?;
+
+        out.write_all(&offset.to_le_bytes())?;
+        out.write_all(&length.to_le_bytes())?;
+        written += 16;
+    }
+
+    for (_, source, _) in entries {
+        let mut file = File::open(source)?;
+        io::copy(&mut file, &mut out)?;
+    }
+
+    Ok(())
+}
+
+/// Read back the index of a `.far` archive without extracting any data.
+pub fn read_index(archive_path: &Path) -> io::Result<Vec<ArchiveEntry>> {
+    let mut data = fs::read(archive_path)?;
+    let mut cursor = 0usize;
+
+    let take = |data: &mut Vec<u8>, cursor: &mut usize, n: usize| -> io::Result<Vec<u8>> {
+        if *cursor + n > data.len() {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated archive"));
+        }
+        let slice = data[*cursor..*cursor + n].to_vec();
+        *cursor += n;
+        Ok(slice)
+    };
+
+    let magic = take(&mut data, &mut cursor, 4)?;
+    if magic != MAGIC {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "not a .far archive"));
+    }
+
+    let count = u32::from_le_bytes(take(&mut data, &mut cursor, 4)?.try_into().unwrap());
+    let mut entries = Vec::with_capacity(count as usize);
+
+    for _ in 0..count {
+        let path_len = u32::from_le_bytes(take(&mut data, &mut cursor, 4)?.try_into().unwrap());
+        let path_bytes = take(&mut data, &mut cursor, path_len as usize)?;
+        let path = String::from_utf8(path_bytes)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "non-utf8 path in archive"))?;
+
+        let written = 4 + path_len as u64;
+        let padding = (8 - (written % 8)) % 8;
+        cursor += padding as usize;
+
+        let offset = u64::from_le_bytes(take(&mut data, &mut cursor, 8)?.try_into().unwrap());
+        let length = u64::from_le_bytes(take(&mut data, &mut cursor, 8)?.try_into().unwrap());
+
+        entries.push(ArchiveEntry { path, offset, length });
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Seek, SeekFrom};
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("fit-archive-test-{}-{}", std::process::id(), name));
+        path
+    }
+
+    #[test]
+    fn round_trips_index_and_data() {
+        let a_path = temp_path("a.txt");
+        let b_path = temp_path("b.txt");
+        fs::write(&a_path, b"hello").unwrap();
+        fs::write(&b_path, b"world!!").unwrap();
+
+        let entries = vec![
+            ("a.txt".to_string(), a_path.as_path(), 5),
+            ("b.txt".to_string(), b_path.as_path(), 7),
+        ];
+
+        let archive_path = temp_path("out.far");
+        write_archive(&archive_path, &entries).unwrap();
+
+        let index = read_index(&archive_path).unwrap();
+        assert_eq!(index.len(), 2);
+        assert_eq!(index[0].path, "a.txt");
+        assert_eq!(index[0].length, 5);
+        assert_eq!(index[1].path, "b.txt");
+        assert_eq!(index[1].length, 7);
+
+        let mut file = File::open(&archive_path).unwrap();
+
+        let mut a_data = vec![0u8; index[0].length as usize];
+        file.seek(SeekFrom::Start(index[0].offset)).unwrap();
+        file.read_exact(&mut a_data).unwrap();
+        assert_eq!(a_data, b"hello");
+
+        let mut b_data = vec![0u8; index[1].length as usize];
+        file.seek(SeekFrom::Start(index[1].offset)).unwrap();
+        file.read_exact(&mut b_data).unwrap();
+        assert_eq!(b_data, b"world!!");
+
+        fs::remove_file(&a_path).ok();
+        fs::remove_file(&b_path).ok();
+        fs::remove_file(&archive_path).ok();
+    }
+
+    #[test]
+    fn rejects_non_far_files() {
+        let path = temp_path("not-an-archive.bin");
+        fs::write(&path, b"not a far file at all").unwrap();
+
+        let err = read_index(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        fs::remove_file(&path).ok();
+    }
+}
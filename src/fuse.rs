@@ -0,0 +1,264 @@
+//! A read-only FUSE view of computed buckets, so users can inspect (or
+//! rsync/burn straight from) the packed layout without ever writing a link
+//! to disk. The top level is the numbered bucket directories; each bucket
+//! contains its members at their relative paths. Reads are served straight
+//! from the original files on disk.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use fuser::{
+    FileAttr, FileType, Filesystem, MountOption, ReplyAttr, ReplyData, ReplyDirectory,
+    ReplyEntry, Request,
+};
+
+const TTL: Duration = Duration::from_secs(1);
+const ROOT_INO: u64 = 1;
+
+/// A single bucket member: where it lives in the virtual tree and which
+/// real file backs its contents.
+pub struct VirtualFile {
+    pub relative_path: PathBuf,
+    pub source: PathBuf,
+    pub size: u64,
+}
+
+/// The whole mountable layout: one entry per bucket, named as it would be
+/// on disk (e.g. `"001"`), holding its members.
+pub struct BucketTree {
+    pub buckets: Vec<(String, Vec<VirtualFile>)>,
+}
+
+struct Node {
+    name: String,
+    parent: u64,
+    is_dir: bool,
+    size: u64,
+    source: Option<PathBuf>,
+    children: Vec<u64>,
+}
+
+/// An in-memory directory tree backing [`Filesystem`], built once from a
+/// [`BucketTree`] and served read-only for the life of the mount.
+pub struct BucketFs {
+    nodes: HashMap<u64, Node>,
+}
+
+impl BucketFs {
+    pub fn new(tree: BucketTree) -> Self {
+        let mut nodes = HashMap::new();
+        nodes.insert(
+            ROOT_INO,
+            Node {
+                name: "/".to_string(),
+                parent: ROOT_INO,
+                is_dir: true,
+                size: 0,
+                source: None,
+                children: Vec::new(),
+            },
+        );
+
+        let mut next_ino = ROOT_INO + 1;
+        for (bucket_name, files) in tree.buckets {
+            let bucket_ino = next_ino;
+            next_ino += 1;
+            nodes.insert(
+                bucket_ino,
+                Node {
+                    name: bucket_name,
+                    parent: ROOT_INO,
+                    is_dir: true,
+                    size: 0,
+                    source: None,
+                    children: Vec::new(),
+                },
+            );
+            nodes.get_mut(&ROOT_INO).unwrap().children.push(bucket_ino);
+
+            for file in files {
+                insert_file(&mut nodes, &mut next_ino, bucket_ino, &file);
+            }
+        }
+
+        BucketFs { nodes }
+    }
+
+    fn lookup_child(&self, parent: u64, name: &str) -> Option<u64> {
+        self.nodes[&parent]
+            .children
+            .iter()
+            .copied()
+            .find(|child| self.nodes[child].name == name)
+    }
+
+    fn attr(&self, ino: u64) -> FileAttr {
+        let node = &self.nodes[&ino];
+        let kind = if node.is_dir { FileType::Directory } else { FileType::RegularFile };
+        let now = std::time::SystemTime::UNIX_EPOCH;
+
+        FileAttr {
+            ino,
+            size: node.size,
+            blocks: node.size.div_ceil(512),
+            atime: now,
+            mtime: now,
+            ctime: now,
+            crtime: now,
+            kind,
+            perm: if node.is_dir { 0o555 } else { 0o444 },
+            nlink: 1,
+            uid: 0,
+            gid: 0,
+            rdev: 0,
+            blksize: 512,
+            flags: 0,
+        }
+    }
+}
+
+/// Create (or reuse) the directory nodes for every path component of
+/// `file.relative_path` and attach a leaf node for the file itself, so
+/// nested recursive/`--keep-dirs` layouts show up as real subdirectories.
+fn insert_file(nodes: &mut HashMap<u64, Node>, next_ino: &mut u64, bucket_ino: u64, file: &VirtualFile) {
+    let mut parent = bucket_ino;
+    let components: Vec<&OsStr> = file
+        .relative_path
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => Some(name),
+            _ => None,
+        })
+        .collect();
+
+    for component in &components[..components.len().saturating_sub(1)] {
+        let name = component.to_string_lossy().into_owned();
+        parent = match nodes[&parent].children.iter().copied().find(|c| nodes[c].name == name) {
+            Some(existing) => existing,
+            None => {
+                let ino = *next_ino;
+                *next_ino += 1;
+                nodes.insert(
+                    ino,
+                    Node {
+                        name,
+                        parent,
+                        is_dir: true,
+                        size: 0,
+                        source: None,
+                        children: Vec::new(),
+                    },
+                );
+                nodes.get_mut(&parent).unwrap().children.push(ino);
+                ino
+            }
+        };
+    }
+
+    let ino = *next_ino;
+    *next_ino += 1;
+    let name = components
+        .last()
+        .map_or_else(|| file.relative_path.to_string_lossy().into_owned(), |c| c.to_string_lossy().into_owned());
+    nodes.insert(
+        ino,
+        Node {
+            name,
+            parent,
+            is_dir: false,
+            size: file.size,
+            source: Some(file.source.clone()),
+            children: Vec::new(),
+        },
+    );
+    nodes.get_mut(&parent).unwrap().children.push(ino);
+}
+
+impl Filesystem for BucketFs {
+    fn lookup(&mut self, _req: &Request, parent: u64, name: &OsStr, reply: ReplyEntry) {
+        match self.lookup_child(parent, &name.to_string_lossy()) {
+            Some(ino) => reply.entry(&TTL, &self.attr(ino), 0),
+            None => reply.error(libc::ENOENT),
+        }
+    }
+
+    fn getattr(&mut self, _req: &Request, ino: u64, reply: ReplyAttr) {
+        match self.nodes.get(&ino) {
+            Some(_) => reply.attr(&TTL, &self.attr(ino)),
+            None => reply.error(libc::ENOENT),
+        }
+    }
+
+    fn read(
+        &mut self,
+        _req: &Request,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        size: u32,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        reply: ReplyData,
+    ) {
+        let Some(node) = self.nodes.get(&ino) else {
+            reply.error(libc::ENOENT);
+            return;
+        };
+        let Some(source) = &node.source else {
+            reply.error(libc::EISDIR);
+            return;
+        };
+
+        match read_at(source, offset, size) {
+            Ok(data) => reply.data(&data),
+            Err(_) => reply.error(libc::EIO),
+        }
+    }
+
+    fn readdir(&mut self, _req: &Request, ino: u64, _fh: u64, offset: i64, mut reply: ReplyDirectory) {
+        let Some(node) = self.nodes.get(&ino) else {
+            reply.error(libc::ENOENT);
+            return;
+        };
+        if !node.is_dir {
+            reply.error(libc::ENOTDIR);
+            return;
+        }
+
+        let mut entries = vec![(ino, FileType::Directory, ".".to_string()), (node.parent, FileType::Directory, "..".to_string())];
+        for &child in &node.children {
+            let child_node = &self.nodes[&child];
+            let kind = if child_node.is_dir { FileType::Directory } else { FileType::RegularFile };
+            entries.push((child, kind, child_node.name.clone()));
+        }
+
+        for (i, (ino, kind, name)) in entries.into_iter().enumerate().skip(offset as usize) {
+            if reply.add(ino, (i + 1) as i64, kind, name) {
+                break;
+            }
+        }
+        reply.ok();
+    }
+}
+
+fn read_at(source: &Path, offset: i64, size: u32) -> io::Result<Vec<u8>> {
+    let mut file = File::open(source)?;
+    file.seek(SeekFrom::Start(offset as u64))?;
+
+    let mut buf = vec![0u8; size as usize];
+    let read = file.read(&mut buf)?;
+    buf.truncate(read);
+    Ok(buf)
+}
+
+/// Mount `tree` read-only at `mountpoint`, blocking until the filesystem is
+/// unmounted (e.g. via `umount`/ctrl-c).
+pub fn mount(tree: BucketTree, mountpoint: &Path) -> io::Result<()> {
+    let options = [MountOption::RO, MountOption::FSName("fit".to_string())];
+    fuser::mount2(BucketFs::new(tree), mountpoint, &options)
+}
@@ -1,11 +1,55 @@
+mod archive;
+/// Read-only FUSE view of computed buckets. Pulls in `fuser`/`libc`, a
+/// system FUSE library dependency that most users of this CLI don't need,
+/// so it's opt-in via the `fuse` Cargo feature rather than built by default.
+#[cfg(feature = "fuse")]
+mod fuse;
+
+use std::collections::VecDeque;
 use std::error;
 use std::fmt;
 use std::fs;
 use std::io;
+use std::os::unix::fs::FileTypeExt;
 use std::path::{Path, PathBuf};
+use std::process::Command as Process;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Pack files from a source directory into size-bounded buckets.
+    Pack(Config),
+    /// Print the index of a previously written `.far` archive.
+    List {
+        /// Path to the `.far` archive to inspect.
+        archive: PathBuf,
+    },
+}
+
+/// How a packed bucket is materialized on disk.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputMode {
+    /// Hard-link each file into the bucket directory (default, same filesystem only).
+    HardLink,
+    /// Copy each file into the bucket directory.
+    Copy,
+    /// Symlink each file into the bucket directory.
+    Symlink,
+    /// Serialize the whole bucket into a single `NNN.far` archive.
+    Archive,
+}
 
 #[derive(Parser, Debug)]
 struct Config {
@@ -13,24 +57,181 @@ struct Config {
     source_directory: PathBuf,
     #[arg(short, long, default_value = "part")]
     link_destination: PathBuf,
-    #[arg(short, long, default_value_t = 15_000_000)]
+    #[arg(short, long, value_parser = parse_human_size, default_value = "15000000")]
     bucket_capacity: u64,
+    /// Use the usable capacity of common optical media instead of --bucket-capacity.
+    #[arg(short, long, value_enum)]
+    media: Option<Media>,
+    /// Percentage of --media's capacity to reserve for filesystem overhead.
+    #[arg(long, default_value_t = 0.0)]
+    overhead: f64,
     #[arg(short, long, action)]
     recursive: bool,
     #[arg(short, long, action)]
     dry_run: bool,
     #[arg(short, long, action)]
     verbose: bool,
+    #[arg(short, long, value_enum, default_value = "hard-link")]
+    output_mode: OutputMode,
+    #[arg(short, long, action)]
+    keep_dirs: bool,
+    #[arg(long, value_enum, default_value = "ffd")]
+    strategy: Strategy,
+    /// Worker threads for file collection (defaults to available cores).
+    #[arg(short, long)]
+    jobs: Option<usize>,
+    /// Resolve and size symlink targets instead of recreating the symlink itself.
+    #[arg(short, long, action)]
+    follow_symlinks: bool,
+    /// Mount the computed buckets read-only at this path instead of writing them out.
+    #[arg(long)]
+    mount: Option<PathBuf>,
+}
+
+/// Bin-packing placement rule, applied after sorting items largest-first.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Strategy {
+    /// First-fit-decreasing: drop each item into the first bucket it fits.
+    Ffd,
+    /// Best-fit-decreasing: use the bucket left with the least remaining room.
+    Bfd,
+    /// Worst-fit-decreasing: use the bucket left with the most remaining room.
+    Wfd,
+}
+
+/// Common optical media, by usable capacity in bytes.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Media {
+    Cd,
+    Dvd,
+    #[value(name = "dvd-dl")]
+    DvdDl,
+    Bd,
+    #[value(name = "bd-dl")]
+    BdDl,
+}
+
+impl Media {
+    fn capacity(self) -> u64 {
+        match self {
+            Media::Cd => 700_000_000,
+            Media::Dvd => 4_380_000_000,
+            Media::DvdDl => 7_950_000_000,
+            Media::Bd => 23_300_000_000,
+            Media::BdDl => 46_600_000_000,
+        }
+    }
+}
+
+fn parse_human_size(s: &str) -> Result<u64, String> {
+    s.parse::<HumanSize>().map(|size| size.0)
+}
+
+/// What an entry actually is on disk, so the output backend can recreate it
+/// faithfully instead of assuming everything is a regular file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EntryKind {
+    Regular,
+    Symlink,
+    Fifo,
+    BlockDevice,
+    CharDevice,
+}
+
+impl EntryKind {
+    /// Short tag shown next to non-regular entries in dry-run output, so a
+    /// preview can tell a symlink or device node apart from a genuine
+    /// regular file instead of showing them as identical size/path lines.
+    fn tag(self) -> Option<&'static str> {
+        match self {
+            EntryKind::Regular => None,
+            EntryKind::Symlink => Some("symlink"),
+            EntryKind::Fifo => Some("fifo"),
+            EntryKind::BlockDevice => Some("block device"),
+            EntryKind::CharDevice => Some("char device"),
+        }
+    }
 }
 
 struct FileInfo {
     path: PathBuf,
+    /// Where to actually read bytes/metadata from when materializing this
+    /// entry. Equal to `path` except for a followed symlink, where `path`
+    /// keeps the symlink's own location (for naming and display) while
+    /// `source` points at the resolved target.
+    source: PathBuf,
     size: u64,
+    kind: EntryKind,
 }
 
 impl fmt::Display for FileInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:-8} {}", HumanSize(self.size), self.path.display())
+        write!(f, "{:-8} {}", HumanSize(self.size), self.path.display())?;
+        if let Some(tag) = self.kind.tag() {
+            write!(f, " ({})", tag)?;
+        }
+        Ok(())
+    }
+}
+
+/// A directory and all of its descendant files (including symlinks and
+/// special files), packed as one
+/// indivisible unit when `--keep-dirs` is set.
+struct Group {
+    root: PathBuf,
+    size: u64,
+    members: Vec<FileInfo>,
+}
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:-8} {}/ ({} files)",
+            HumanSize(self.size),
+            self.root.display(),
+            self.members.len()
+        )
+    }
+}
+
+/// A packable unit: either a single file or a whole directory kept together.
+enum Item {
+    File(FileInfo),
+    Group(Group),
+}
+
+impl Item {
+    fn size(&self) -> u64 {
+        match self {
+            Item::File(file) => file.size,
+            Item::Group(group) => group.size,
+        }
+    }
+
+    fn files(&self) -> Box<dyn Iterator<Item = &FileInfo> + '_> {
+        match self {
+            Item::File(file) => Box::new(std::iter::once(file)),
+            Item::Group(group) => Box::new(group.members.iter()),
+        }
+    }
+
+    /// Path used to break ties when sorting items of equal size, so bucket
+    /// assignment is deterministic regardless of collection order.
+    fn sort_path(&self) -> &Path {
+        match self {
+            Item::File(file) => &file.path,
+            Item::Group(group) => &group.root,
+        }
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Item::File(file) => write!(f, "{}", file),
+            Item::Group(group) => write!(f, "{}", group),
+        }
     }
 }
 
@@ -38,7 +239,7 @@ struct Bucket<'a> {
     path: PathBuf,
     capacity: u64,
     size: u64,
-    contents: Vec<&'a FileInfo>,
+    contents: Vec<&'a Item>,
 }
 
 impl<'a> fmt::Display for Bucket<'a> {
@@ -61,21 +262,52 @@ impl<'a> fmt::Display for Bucket<'a> {
     }
 }
 
+/// One bucket member resolved to its final on-disk shape: where it should
+/// live relative to the bucket root, which real path backs its bytes, and
+/// what kind of filesystem entry to recreate. Every output backend
+/// (hard-link/copy/symlink, archive, and the FUSE view) renders from this
+/// same sequence instead of walking `Bucket::contents` itself, so they can
+/// never disagree about what a bucket contains.
+struct ResolvedFile<'a> {
+    relative_path: &'a Path,
+    source: &'a Path,
+    size: u64,
+    kind: EntryKind,
+}
+
 impl<'a> Bucket<'a> {
-    fn add(&mut self, file: &'a FileInfo) -> bool {
-        if self.size + file.size <= self.capacity {
-            self.contents.push(file);
-            self.size += file.size;
+    fn add(&mut self, item: &'a Item) -> bool {
+        if self.size + item.size() <= self.capacity {
+            self.contents.push(item);
+            self.size += item.size();
             return true;
         }
 
         false
     }
 
-    fn link(self, verbose: bool) -> io::Result<()> {
-        for file in self.contents {
+    fn resolved_files(&self) -> impl Iterator<Item = ResolvedFile<'_>> {
+        self.contents.iter().flat_map(|item| item.files()).map(|file| ResolvedFile {
+            relative_path: &file.path,
+            source: &file.source,
+            size: file.size,
+            kind: file.kind,
+        })
+    }
+
+    fn link(self, mode: OutputMode, verbose: bool) -> io::Result<()> {
+        match mode {
+            OutputMode::Archive => self.archive(verbose),
+            OutputMode::HardLink | OutputMode::Copy | OutputMode::Symlink => {
+                self.link_or_copy(mode, verbose)
+            }
+        }
+    }
+
+    fn link_or_copy(self, mode: OutputMode, verbose: bool) -> io::Result<()> {
+        for file in self.resolved_files() {
             let mut target = self.path.clone();
-            target.push(file.path.clone());
+            target.push(file.relative_path);
             let dir = target.parent().unwrap();
 
             if let Ok(false) = fs::exists(dir) {
@@ -83,13 +315,64 @@ impl<'a> Bucket<'a> {
             }
 
             if verbose {
-                println!("{} -> {}", file.path.display(), target.display());
+                println!("{} -> {}", file.relative_path.display(), target.display());
             }
 
-            fs::hard_link(&file.path, target)?;
+            match file.kind {
+                EntryKind::Symlink => {
+                    std::os::unix::fs::symlink(fs::read_link(file.source)?, target)?;
+                }
+                EntryKind::Fifo | EntryKind::BlockDevice | EntryKind::CharDevice => {
+                    recreate_special_file(file.source, &target)?;
+                }
+                EntryKind::Regular => match mode {
+                    OutputMode::HardLink => fs::hard_link(file.source, target)?,
+                    OutputMode::Copy => {
+                        fs::copy(file.source, target)?;
+                    }
+                    OutputMode::Symlink => std::os::unix::fs::symlink(file.source, target)?,
+                    OutputMode::Archive => unreachable!(),
+                },
+            }
         }
         Ok(())
     }
+
+    fn archive(self, verbose: bool) -> io::Result<()> {
+        let dir = self.path.parent().unwrap();
+        if let Ok(false) = fs::exists(dir) {
+            fs::create_dir_all(dir)?;
+        }
+
+        let archive_path = self.path.with_extension("far");
+
+        // Fifos and device nodes have no meaningful byte stream to archive;
+        // only regular files (and, once resolved, followed symlinks) go in.
+        // Anything else is reported rather than silently dropped, so the
+        // archive's actual contents never quietly diverge from what
+        // --dry-run showed.
+        let (regular, skipped): (Vec<_>, Vec<_>) =
+            self.resolved_files().partition(|file| file.kind == EntryKind::Regular);
+
+        for file in &skipped {
+            println!(
+                "skipping {} ({:?}): archives can only hold regular files",
+                file.relative_path.display(),
+                file.kind
+            );
+        }
+
+        let entries: Vec<(String, &Path, u64)> = regular
+            .iter()
+            .map(|file| (file.relative_path.to_string_lossy().into_owned(), file.source, file.size))
+            .collect();
+
+        if verbose {
+            println!("{} files -> {}", entries.len(), archive_path.display());
+        }
+
+        archive::write_archive(&archive_path, &entries)
+    }
 }
 
 struct HumanSize(pub u64);
@@ -98,11 +381,16 @@ impl FromStr for HumanSize {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
         if s.is_empty() {
             return Err("Empty string".into());
         }
 
-        let (number, suffix) = s.trim().split_at(s.len() - 1);
+        let (number, suffix) = if s.ends_with(|c: char| c.is_ascii_digit()) {
+            (s, "")
+        } else {
+            s.split_at(s.len() - 1)
+        };
         let value: f64 = number.parse().map_err(|_| "Invalid number")?;
         let multiplier = match suffix {
             "k" => 1024.0,
@@ -135,10 +423,93 @@ impl fmt::Display for HumanSize {
     }
 }
 
+/// Recreate a fifo or block/char device node at `target` by shelling out to
+/// `cp -a`, since the standard library has no portable `mkfifo`/`mknod`.
+fn recreate_special_file(source: &Path, target: &Path) -> io::Result<()> {
+    let status = Process::new("cp").arg("-a").arg(source).arg(target).status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "cp -a {} {} failed",
+            source.display(),
+            target.display()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Classify a non-directory `read_dir` entry into a packable [`FileInfo`],
+/// resolving and sizing symlink targets when `follow_symlinks` is set and
+/// otherwise recording special files (symlinks, fifos, device nodes) so the
+/// output backend can recreate them faithfully. Returns `None` for anything
+/// that isn't a regular file, symlink, fifo, or device node.
+fn classify_entry(
+    path: PathBuf,
+    meta: &fs::Metadata,
+    follow_symlinks: bool,
+) -> io::Result<Option<FileInfo>> {
+    let file_type = meta.file_type();
+
+    if file_type.is_file() {
+        return Ok(Some(FileInfo {
+            source: path.clone(),
+            path,
+            size: meta.len(),
+            kind: EntryKind::Regular,
+        }));
+    }
+
+    if file_type.is_symlink() {
+        if !follow_symlinks {
+            return Ok(Some(FileInfo {
+                source: path.clone(),
+                path,
+                size: 0,
+                kind: EntryKind::Symlink,
+            }));
+        }
+
+        let target = fs::canonicalize(&path)?;
+        let target_meta = fs::metadata(&target)?;
+
+        if target_meta.is_dir() {
+            return Err(io::Error::other(format!(
+                "{}: symlink targets a directory, refusing to follow it",
+                path.display()
+            )));
+        }
+
+        return Ok(Some(FileInfo {
+            path,
+            source: target,
+            size: target_meta.len(),
+            kind: EntryKind::Regular,
+        }));
+    }
+
+    let kind = if file_type.is_fifo() {
+        EntryKind::Fifo
+    } else if file_type.is_block_device() {
+        EntryKind::BlockDevice
+    } else if file_type.is_char_device() {
+        EntryKind::CharDevice
+    } else {
+        return Ok(None);
+    };
+
+    Ok(Some(FileInfo {
+        source: path.clone(),
+        path,
+        size: 0,
+        kind,
+    }))
+}
+
 fn collect_files(
     from: &Path,
     recursive: bool,
-    max_size: u64,
+    follow_symlinks: bool,
     files: &mut Vec<FileInfo>,
 ) -> io::Result<()> {
     for entry in fs::read_dir(from)? {
@@ -146,19 +517,167 @@ fn collect_files(
         let meta = entry.metadata()?;
 
         if meta.is_dir() && recursive {
-            collect_files(&entry.path(), recursive, max_size, files)?;
+            collect_files(&entry.path(), recursive, follow_symlinks, files)?;
         }
 
-        if meta.is_file() {
-            files.push(FileInfo {
-                path: entry.path(),
-                size: meta.len(),
+        if let Some(file) = classify_entry(entry.path(), &meta, follow_symlinks)? {
+            files.push(file);
+        }
+    }
+    Ok(())
+}
+
+/// Walk a directory one `read_dir` of work at a time, shared across a pool
+/// of `jobs` worker threads: each worker pops a directory off the queue,
+/// stats its entries, pushes every entry [`classify_entry`] accepts straight into the shared sink
+/// and (when `recursive`) pushes child directories back onto the queue.
+fn collect_files_parallel(
+    from: &Path,
+    recursive: bool,
+    follow_symlinks: bool,
+    jobs: usize,
+) -> io::Result<Vec<FileInfo>> {
+    let queue: Mutex<VecDeque<PathBuf>> = Mutex::new(VecDeque::from([from.to_path_buf()]));
+    let pending = AtomicUsize::new(1);
+    let sink: Mutex<Vec<FileInfo>> = Mutex::new(Vec::new());
+    let error: Mutex<Option<io::Error>> = Mutex::new(None);
+
+    let scan_one = |dir: PathBuf| -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let meta = entry.metadata()?;
+
+            if meta.is_dir() && recursive {
+                pending.fetch_add(1, Ordering::SeqCst);
+                queue.lock().unwrap().push_back(entry.path());
+            }
+
+            if let Some(file) = classify_entry(entry.path(), &meta, follow_symlinks)? {
+                sink.lock().unwrap().push(file);
+            }
+        }
+        Ok(())
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let scan_one = &scan_one;
+            let queue = &queue;
+            let pending = &pending;
+            let error = &error;
+
+            scope.spawn(move || loop {
+                let dir = queue.lock().unwrap().pop_front();
+
+                let dir = match dir {
+                    Some(dir) => dir,
+                    None if pending.load(Ordering::SeqCst) == 0 => break,
+                    None => {
+                        thread::yield_now();
+                        continue;
+                    }
+                };
+
+                if let Err(e) = scan_one(dir) {
+                    *error.lock().unwrap() = Some(e);
+                }
+                pending.fetch_sub(1, Ordering::SeqCst);
             });
         }
+    });
+
+    if let Some(e) = error.into_inner().unwrap() {
+        return Err(e);
+    }
+
+    Ok(sink.into_inner().unwrap())
+}
+
+/// Collect files under `from`, respecting `recursive`, via a serial walk
+/// when `jobs <= 1` and via [`collect_files_parallel`] otherwise.
+fn list_files(
+    from: &Path,
+    recursive: bool,
+    follow_symlinks: bool,
+    jobs: usize,
+) -> io::Result<Vec<FileInfo>> {
+    if jobs <= 1 {
+        let mut files = Vec::new();
+        collect_files(from, recursive, follow_symlinks, &mut files)?;
+        Ok(files)
+    } else {
+        collect_files_parallel(from, recursive, follow_symlinks, jobs)
+    }
+}
+
+/// Collect top-level entries of `from` as packable [`Item`]s. When `keep_dirs`
+/// is set, a top-level subdirectory becomes a single [`Item::Group`] holding
+/// all of its descendant files (regardless of `recursive`); otherwise
+/// directories are only descended into when `recursive` is set, and every
+/// file found (regular, symlink, fifo or device node) becomes its own [`Item::File`].
+fn collect_items(
+    from: &Path,
+    recursive: bool,
+    keep_dirs: bool,
+    follow_symlinks: bool,
+    jobs: usize,
+    items: &mut Vec<Item>,
+) -> io::Result<()> {
+    if !keep_dirs {
+        items.extend(
+            list_files(from, recursive, follow_symlinks, jobs)?
+                .into_iter()
+                .map(Item::File),
+        );
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let meta = entry.metadata()?;
+
+        if meta.is_dir() {
+            let members = list_files(&entry.path(), true, follow_symlinks, jobs)?;
+            let size = members.iter().map(|file| file.size).sum();
+            items.push(Item::Group(Group {
+                root: entry.path(),
+                size,
+                members,
+            }));
+        }
+
+        if let Some(file) = classify_entry(entry.path(), &meta, follow_symlinks)? {
+            items.push(Item::File(file));
+        }
     }
     Ok(())
 }
 
+/// Place `item` into `buckets` according to `strategy`, returning `false`
+/// when no existing bucket can hold it.
+fn place<'a>(buckets: &mut [Bucket<'a>], item: &'a Item, strategy: Strategy) -> bool {
+    let candidate = match strategy {
+        Strategy::Ffd => buckets.iter().position(|bucket| bucket.size + item.size() <= bucket.capacity),
+        Strategy::Bfd => buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, bucket)| bucket.size + item.size() <= bucket.capacity)
+            .min_by_key(|(_, bucket)| bucket.capacity - bucket.size)
+            .map(|(i, _)| i),
+        Strategy::Wfd => buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, bucket)| bucket.size + item.size() <= bucket.capacity)
+            .max_by_key(|(_, bucket)| bucket.capacity - bucket.size)
+            .map(|(i, _)| i),
+    };
+
+    match candidate {
+        Some(i) => buckets[i].add(item),
+        None => false,
+    }
+}
+
 fn numbered_dir_namer(prefix: &str) -> impl FnMut() -> PathBuf {
     let mut count: u64 = 0;
 
@@ -169,57 +688,100 @@ fn numbered_dir_namer(prefix: &str) -> impl FnMut() -> PathBuf {
 }
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let cfg = Config::parse();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Pack(cfg) => pack(cfg),
+        Command::List { archive } => list(&archive),
+    }
+}
+
+fn list(archive_path: &Path) -> Result<(), Box<dyn error::Error>> {
+    for entry in archive::read_index(archive_path)? {
+        let file = FileInfo {
+            path: PathBuf::from(entry.path.clone()),
+            source: PathBuf::from(entry.path),
+            size: entry.length,
+            kind: EntryKind::Regular,
+        };
+        println!("{:-10} {}", entry.offset, file);
+    }
+
+    Ok(())
+}
 
-    let mut files: Vec<FileInfo> = Vec::new();
-    collect_files(
+fn pack(cfg: Config) -> Result<(), Box<dyn error::Error>> {
+    let jobs = cfg
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+
+    let bucket_capacity = match cfg.media {
+        Some(media) => (media.capacity() as f64 * (1.0 - cfg.overhead / 100.0)) as u64,
+        None => cfg.bucket_capacity,
+    };
+
+    let mut items: Vec<Item> = Vec::new();
+    collect_items(
         &cfg.source_directory,
         cfg.recursive,
-        cfg.bucket_capacity,
-        &mut files,
+        cfg.keep_dirs,
+        cfg.follow_symlinks,
+        jobs,
+        &mut items,
     )?;
 
-    if files.len() < 1 {
+    if items.is_empty() {
         return Err(format!("No files found in {}.", cfg.source_directory.display()).into());
     }
 
-    files.sort_by(|a, b| b.size.cmp(&a.size));
-    if files[0].size > cfg.bucket_capacity {
-        return Err(format!(
-            "Can never fit {} ({}).",
-            files[0].path.display(),
-            HumanSize(files[0].size)
-        )
+    items.sort_by(|a, b| b.size().cmp(&a.size()).then_with(|| a.sort_path().cmp(b.sort_path())));
+    if items[0].size() > bucket_capacity {
+        return Err(match &items[0] {
+            Item::Group(group) => format!(
+                "Can never fit group {} ({}).",
+                group.root.display(),
+                HumanSize(group.size)
+            ),
+            Item::File(file) => format!(
+                "Can never fit {} ({}).",
+                file.path.display(),
+                HumanSize(file.size)
+            ),
+        }
         .into());
     }
 
     let mut buckets: Vec<Bucket> = Vec::new();
     let mut new_bucket_name = numbered_dir_namer(cfg.link_destination.to_str().unwrap());
-    for file in &files {
-        let mut added = false;
-
-        for bucket in &mut buckets {
-            if bucket.add(&file) {
-                added = true;
-                break;
-            }
-        }
+    for item in &items {
+        let added = place(&mut buckets, item, cfg.strategy);
 
         if !added {
             buckets.push(Bucket {
                 path: new_bucket_name(),
-                capacity: cfg.bucket_capacity,
-                size: file.size,
-                contents: [file].to_vec(),
+                capacity: bucket_capacity,
+                size: item.size(),
+                contents: [item].to_vec(),
             });
         }
     }
 
+    if let Some(mountpoint) = &cfg.mount {
+        #[cfg(feature = "fuse")]
+        return fuse::mount(bucket_tree(&buckets), mountpoint).map_err(Into::into);
+
+        #[cfg(not(feature = "fuse"))]
+        {
+            let _ = mountpoint;
+            return Err("fit was built without FUSE support; rebuild with `--features fuse`.".into());
+        }
+    }
+
     for bucket in buckets {
         if cfg.dry_run {
             println!("{}", bucket);
         } else {
-            if let Err(e) = bucket.link(cfg.verbose) {
+            if let Err(e) = bucket.link(cfg.output_mode, cfg.verbose) {
                 println!("{}", e);
             }
         }
@@ -227,3 +789,95 @@ fn main() -> Result<(), Box<dyn error::Error>> {
 
     Ok(())
 }
+
+/// Render computed buckets as the abstract tree [`fuse::mount`] serves,
+/// without materializing anything on disk. Built from the same
+/// [`Bucket::resolved_files`] sequence that the hard-link/copy and archive
+/// backends consume, so the FUSE view lists the same members. Every member
+/// is served as a regular file of its recorded size: symlinks, fifos and
+/// device nodes are not shown as their own kind, and members that the
+/// archive backend skips still appear here.
+#[cfg(feature = "fuse")]
+fn bucket_tree(buckets: &[Bucket]) -> fuse::BucketTree {
+    let buckets = buckets
+        .iter()
+        .map(|bucket| {
+            let name = bucket.path.file_name().unwrap().to_string_lossy().into_owned();
+            let files = bucket
+                .resolved_files()
+                .map(|file| fuse::VirtualFile {
+                    relative_path: file.relative_path.to_path_buf(),
+                    source: file.source.to_path_buf(),
+                    size: file.size,
+                })
+                .collect();
+            (name, files)
+        })
+        .collect();
+
+    fuse::BucketTree { buckets }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_item(size: u64) -> Item {
+        Item::File(FileInfo {
+            path: PathBuf::from(format!("item-{size}")),
+            source: PathBuf::from(format!("item-{size}")),
+            size,
+            kind: EntryKind::Regular,
+        })
+    }
+
+    fn empty_bucket<'a>(capacity: u64) -> Bucket<'a> {
+        Bucket {
+            path: PathBuf::from("bucket"),
+            capacity,
+            size: 0,
+            contents: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn ffd_uses_first_bucket_that_fits() {
+        let item = file_item(4);
+        let mut buckets = vec![empty_bucket(10), empty_bucket(10)];
+
+        assert!(place(&mut buckets, &item, Strategy::Ffd));
+        assert_eq!(buckets[0].size, 4);
+        assert_eq!(buckets[1].size, 0);
+    }
+
+    #[test]
+    fn bfd_uses_bucket_with_least_remaining_room() {
+        let item = file_item(4);
+        // Bucket 0 has 10 bytes free, bucket 1 has 5: bfd should prefer the
+        // tighter fit that still has room, not the first one that fits.
+        let mut buckets = vec![empty_bucket(10), empty_bucket(5)];
+
+        assert!(place(&mut buckets, &item, Strategy::Bfd));
+        assert_eq!(buckets[0].size, 0);
+        assert_eq!(buckets[1].size, 4);
+    }
+
+    #[test]
+    fn wfd_uses_bucket_with_most_remaining_room() {
+        let item = file_item(4);
+        let mut buckets = vec![empty_bucket(5), empty_bucket(10)];
+
+        assert!(place(&mut buckets, &item, Strategy::Wfd));
+        assert_eq!(buckets[0].size, 0);
+        assert_eq!(buckets[1].size, 4);
+    }
+
+    #[test]
+    fn place_reports_failure_when_nothing_fits() {
+        let item = file_item(20);
+        let mut buckets = vec![empty_bucket(10)];
+
+        assert!(!place(&mut buckets, &item, Strategy::Ffd));
+        assert_eq!(buckets[0].size, 0);
+    }
+}